@@ -49,6 +49,13 @@ impl HashedUserId {
             s_bs58: s.to_string(),
         }
     }
+
+    /// Derive a relayed signer's identity from their public key, not a NEAR account.
+    pub fn from_public_key(public_key: &[u8]) -> Self {
+        Self {
+            s_bs58: bs58::encode(sha256(public_key)).into_string(),
+        }
+    }
 }
 
 /// A record for pending trust request
@@ -56,6 +63,20 @@ impl HashedUserId {
 pub struct TrustRequest {
     pub deposit: NearToken,
     pub expiry: u64,
+    // The requester's real account, kept alongside the hashed key so the
+    // deposit can be refunded to them (not derivable from a sha256 hash).
+    pub requester: AccountId,
+}
+
+/// Canonical signed payload for `trust_signed`/`block_signed`; binds the contract
+/// id and nonce in so a signature can't be replayed elsewhere or twice.
+#[derive(BorshSerialize)]
+struct SignedAction {
+    action: String,
+    user_id: String,
+    level: f32,
+    nonce: u64,
+    contract_id: String,
 }
 
 /// A "view-friendly" version of `UserData` suitable for JSON responses.
@@ -69,6 +90,7 @@ pub struct UserDataView {
     // Sub-maps become simple vectors
     pub trust_network: Vec<(String, f32)>,
     pub blocked_users: Vec<String>,
+    pub incoming_trust_sum: f32,
 }
 
 /// The user's data stored in the contract, keyed by hashed user ID.
@@ -82,6 +104,9 @@ pub struct UserData {
     // Sub-collections become Vec
     pub trust_network: Vec<(String, f32)>,
     pub blocked_users: Vec<String>,
+
+    // Cached sum of incoming trust weights, kept in sync incrementally.
+    pub incoming_trust_sum: f32,
 }
 
 impl UserData {
@@ -92,6 +117,7 @@ impl UserData {
             public_profile: String::new(),
             trust_network: Vec::new(),
             blocked_users: Vec::new(),
+            incoming_trust_sum: 0.0,
         }
     }
 
@@ -173,6 +199,15 @@ pub struct CentralLinkOfTrustContract {
 
     // Maximum expiry offset in nanoseconds
     timeout_duration: u64,
+
+    // hashedUserId (derived from a relayed signer's public key) -> last used nonce
+    signer_nonces: IterableMap<HashedUserId, u64>,
+
+    // (requester, target) -> pending handshake awaiting the target's decision
+    pending_requests: IterableMap<(HashedUserId, HashedUserId), TrustRequest>,
+
+    // hashedUserId -> denormalized "who trusts me" edges, mirroring trust_network
+    incoming_trust: IterableMap<HashedUserId, Vec<(String, f32)>>,
 }
 
 impl Default for CentralLinkOfTrustContract {
@@ -181,6 +216,9 @@ impl Default for CentralLinkOfTrustContract {
             users: IterableMap::new(b"u".to_vec()),
             user_deposits: IterableMap::new(b"d".to_vec()),
             timeout_duration: 7 * 24 * 60 * 60 * 1_000_000_000, // 7 days
+            signer_nonces: IterableMap::new(b"n".to_vec()),
+            pending_requests: IterableMap::new(b"p".to_vec()),
+            incoming_trust: IterableMap::new(b"i".to_vec()),
         }
     }
 }
@@ -229,6 +267,46 @@ impl CentralLinkOfTrustContract {
         self.users.insert(hashed_id.clone(), user_data);
     }
 
+    /// Mirror one outgoing edge change into `incoming_trust` / `incoming_trust_sum`.
+    /// `new_level` is `None` to remove the edge, `Some(level)` to add/update it.
+    /// Never creates a `UserData` record for `trusted_id` — only an existing,
+    /// already-deposited user's cached sum is updated.
+    fn sync_incoming_trust(
+        &mut self,
+        truster_id: &HashedUserId,
+        trusted_id: &HashedUserId,
+        new_level: Option<f32>,
+    ) {
+        let mut mirrored = self.incoming_trust.remove(trusted_id).unwrap_or_default();
+        let mut delta = 0.0f32;
+
+        match new_level {
+            Some(level) => {
+                if let Some(existing) = mirrored.iter_mut().find(|(k, _)| *k == truster_id.s_bs58) {
+                    delta += level - existing.1;
+                    existing.1 = level;
+                } else {
+                    delta += level;
+                    mirrored.push((truster_id.s_bs58.clone(), level));
+                }
+            }
+            None => {
+                if let Some(idx) = mirrored.iter().position(|(k, _)| *k == truster_id.s_bs58) {
+                    delta -= mirrored[idx].1;
+                    mirrored.remove(idx);
+                }
+            }
+        }
+
+        self.incoming_trust.insert(trusted_id.clone(), mirrored);
+
+        if delta != 0.0 && self.users.get(trusted_id).is_some() {
+            self.with_user_data(trusted_id, |user_data| {
+                user_data.incoming_trust_sum += delta;
+            });
+        }
+    }
+
     /// Compare the updated storage usage to the userâ€™s deposit.  Refund or require more if needed.
     fn verify_deposit(&mut self, hashed_id: HashedUserId) {
         let new_size = self.measure_storage_usage(&hashed_id, &self.users[&hashed_id]);
@@ -331,6 +409,7 @@ impl CentralLinkOfTrustContract {
                 trust_network: user.trust_network.clone(),
 
                 blocked_users: user.blocked_users.iter().map(|k| k.clone()).collect(),
+                incoming_trust_sum: user.incoming_trust_sum,
             })
         } else {
             None
@@ -347,6 +426,15 @@ impl CentralLinkOfTrustContract {
         }
     }
 
+    /// Return everyone who currently trusts `user_id`, and at what level.
+    pub fn get_trusters(&self, user_id: String) -> Vec<(String, f32)> {
+        let h_user_id = HashedUserId::from_bs58(&user_id);
+        self.incoming_trust
+            .get(&h_user_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     // trust level = 0..1
     #[payable]
     pub fn trust(&mut self, user_id: String, level: f32) {
@@ -368,6 +456,11 @@ impl CentralLinkOfTrustContract {
                 user_data.insert_trust_network(h_trusted_id.s_bs58.clone(), level);
             }
         });
+        self.sync_incoming_trust(
+            &caller_id,
+            &h_trusted_id,
+            if level == 0.0 { None } else { Some(level) },
+        );
         self.verify_deposit(caller_id);
     }
 
@@ -378,6 +471,7 @@ impl CentralLinkOfTrustContract {
         self.with_user_data(&caller_id, |user_data| {
             user_data.remove_trust_network(&h_trusted_id.s_bs58);
         });
+        self.sync_incoming_trust(&caller_id, &h_trusted_id, None);
         self.verify_deposit(caller_id);
     }
 
@@ -396,6 +490,7 @@ impl CentralLinkOfTrustContract {
             self.with_user_data(&h_other_id, |user_data| {
                 user_data.remove_trust_network(&caller_id.s_bs58);
             });
+            self.sync_incoming_trust(&h_other_id, &caller_id, None);
         }
         self.verify_deposit(caller_id);
     }
@@ -411,15 +506,435 @@ impl CentralLinkOfTrustContract {
         self.verify_deposit(caller_id);
     }
 
+    // ----------------------------------
+    // DELEGATED (META-TRANSACTION) OPS
+    // ----------------------------------
+
+    /// Verify a relayed signature and nonce, returning the signer's identity.
+    fn verify_signed_action(
+        &mut self,
+        action: &str,
+        user_id: &str,
+        level: f32,
+        nonce: u64,
+        signer_public_key: &[u8],
+        signature: &[u8],
+    ) -> HashedUserId {
+        let signer_id = HashedUserId::from_public_key(signer_public_key);
+
+        let last_nonce = *self.signer_nonces.get(&signer_id).unwrap_or(&0);
+        require!(nonce > last_nonce, "ERR_NONCE_REPLAYED");
+
+        let message = SignedAction {
+            action: action.to_string(),
+            user_id: user_id.to_string(),
+            level,
+            nonce,
+            contract_id: env::current_account_id().to_string(),
+        };
+        let encoded = borsh::to_vec(&message).unwrap();
+
+        let sig: [u8; 64] = signature
+            .try_into()
+            .unwrap_or_else(|_| env::panic_str("ERR_INVALID_SIGNATURE_LEN"));
+        let pk: [u8; 32] = signer_public_key
+            .try_into()
+            .unwrap_or_else(|_| env::panic_str("ERR_INVALID_PUBLIC_KEY_LEN"));
+
+        require!(
+            env::ed25519_verify(&sig, &encoded, &pk),
+            "ERR_INVALID_SIGNATURE"
+        );
+
+        self.signer_nonces.insert(signer_id.clone(), nonce);
+        signer_id
+    }
+
+    /// Relayer-submitted equivalent of `trust`, authorized by signature instead of `predecessor_account_id`.
+    #[payable]
+    pub fn trust_signed(
+        &mut self,
+        user_id: String,
+        level: f32,
+        signer_public_key: Vec<u8>,
+        nonce: u64,
+        signature: Vec<u8>,
+    ) {
+        require!(level >= 0.0 && level <= 1.0, "Invalid trust level");
+        let signer_id =
+            self.verify_signed_action("trust", &user_id, level, nonce, &signer_public_key, &signature);
+        let h_trusted_id = HashedUserId::from_bs58(&user_id);
+
+        if let Some(target_user) = self.users.get(&h_trusted_id) {
+            if target_user.is_blocked(&signer_id.s_bs58) {
+                env::panic_str("You are blocked");
+            }
+        }
+
+        self.with_user_data(&signer_id, |user_data| {
+            if level == 0.0 {
+                user_data.remove_trust_network(&h_trusted_id.s_bs58);
+            } else {
+                user_data.insert_trust_network(h_trusted_id.s_bs58.clone(), level);
+            }
+        });
+        self.sync_incoming_trust(
+            &signer_id,
+            &h_trusted_id,
+            if level == 0.0 { None } else { Some(level) },
+        );
+        self.verify_deposit(signer_id);
+    }
+
+    /// Relayer-submitted equivalent of `block_user`, authorized like `trust_signed`.
+    #[payable]
+    pub fn block_signed(
+        &mut self,
+        other_id: String,
+        signer_public_key: Vec<u8>,
+        nonce: u64,
+        signature: Vec<u8>,
+    ) {
+        let signer_id =
+            self.verify_signed_action("block", &other_id, 0.0, nonce, &signer_public_key, &signature);
+        let h_other_id = HashedUserId::from_bs58(&other_id);
+
+        self.with_user_data(&signer_id, |user_data| {
+            user_data.block_user(h_other_id.s_bs58.clone());
+        });
+
+        if self.users.get(&h_other_id).is_some() {
+            self.with_user_data(&h_other_id, |user_data| {
+                user_data.remove_trust_network(&signer_id.s_bs58);
+            });
+            self.sync_incoming_trust(&h_other_id, &signer_id, None);
+        }
+        self.verify_deposit(signer_id);
+    }
+
+    // -------------
+    // GLOBAL REPUTATION
+    // -------------
+
+    /// EigenTrust-style power iteration over `trust_network` edges, ranking
+    /// every user from `pre_trusted` seeds (or uniformly, if empty).
+    pub fn compute_global_trust(
+        &self,
+        pre_trusted: Vec<String>,
+        iterations: u32,
+    ) -> Vec<(String, f32)> {
+        const DAMPING: f32 = 0.15;
+        const L1_EPSILON: f32 = 1e-6;
+
+        let user_ids: Vec<HashedUserId> = self.users.iter().map(|(id, _)| id.clone()).collect();
+        let n = user_ids.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // O(1) target lookup so the matrix build below is bounded by edges, not
+        // edges * users.
+        let index_of: std::collections::HashMap<&str, usize> = user_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.s_bs58.as_str(), i))
+            .collect();
+
+        let pre_trusted_idx: Vec<usize> = pre_trusted
+            .iter()
+            .filter_map(|s| index_of.get(s.as_str()).copied())
+            .collect();
+
+        let mut p = vec![0.0f32; n];
+        if pre_trusted_idx.is_empty() {
+            let uniform = 1.0 / n as f32;
+            p.iter_mut().for_each(|v| *v = uniform);
+        } else {
+            let uniform = 1.0 / pre_trusted_idx.len() as f32;
+            for &i in &pre_trusted_idx {
+                p[i] = uniform;
+            }
+        }
+
+        // Row-normalized local trust matrix, stored sparsely as (target, weight) per row.
+        let mut rows: Vec<Vec<(usize, f32)>> = Vec::with_capacity(n);
+        for (i, id) in user_ids.iter().enumerate() {
+            let user = &self.users[id];
+            let mut edges: Vec<(usize, f32)> = Vec::new();
+            let mut sum = 0.0f32;
+            for (target, weight) in &user.trust_network {
+                let j = match index_of.get(target.as_str()) {
+                    Some(&j) if j != i => j,
+                    _ => continue,
+                };
+                if self.users[&user_ids[j]].is_blocked(&id.s_bs58) {
+                    continue;
+                }
+                edges.push((j, *weight));
+                sum += *weight;
+            }
+            if sum > 0.0 {
+                for e in edges.iter_mut() {
+                    e.1 /= sum;
+                }
+                rows.push(edges);
+            } else {
+                rows.push(
+                    p.iter()
+                        .enumerate()
+                        .filter(|(_, v)| **v > 0.0)
+                        .map(|(j, v)| (j, *v))
+                        .collect(),
+                );
+            }
+        }
+
+        let mut t = p.clone();
+        for _ in 0..iterations {
+            let mut next = vec![0.0f32; n];
+            for (i, edges) in rows.iter().enumerate() {
+                let ti = t[i];
+                if ti == 0.0 {
+                    continue;
+                }
+                for &(j, w) in edges {
+                    next[j] += ti * w;
+                }
+            }
+            let mut l1 = 0.0f32;
+            for j in 0..n {
+                next[j] = (1.0 - DAMPING) * next[j] + DAMPING * p[j];
+                l1 += (next[j] - t[j]).abs();
+            }
+            t = next;
+            if l1 < L1_EPSILON {
+                break;
+            }
+        }
+
+        let total: f32 = t.iter().sum();
+        user_ids
+            .into_iter()
+            .zip(t.into_iter())
+            .map(|(id, v)| (id.s_bs58, if total > 0.0 { v / total } else { 0.0 }))
+            .collect()
+    }
+
+    /// Dijkstra search for the highest-confidence `trust_network` path from
+    /// `from` to `to`, bounded to `max_depth` hops. `None` if none exists.
+    pub fn strongest_trust_path(
+        &self,
+        from: String,
+        to: String,
+        max_depth: u32,
+    ) -> Option<(Vec<String>, f32)> {
+        let from_id = HashedUserId::from_bs58(&from);
+        let to_id = HashedUserId::from_bs58(&to);
+
+        if from_id == to_id {
+            return Some((vec![from_id.s_bs58], 1.0));
+        }
+
+        // Best known -ln(confidence) cost to reach each node, and the parent
+        // edge that achieved it, both as plain Vecs (no IterableMap needed
+        // for search-local, non-persisted state).
+        let mut best_cost: Vec<(HashedUserId, f32)> = vec![(from_id.clone(), 0.0)];
+        let mut parent_of: Vec<(HashedUserId, HashedUserId)> = Vec::new();
+        let mut visited: Vec<HashedUserId> = Vec::new();
+        let mut frontier: Vec<(HashedUserId, f32, u32)> = vec![(from_id.clone(), 0.0, 0)];
+
+        while !frontier.is_empty() {
+            let idx = frontier
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.1.partial_cmp(&b.1).unwrap())
+                .map(|(i, _)| i)
+                .unwrap();
+            let (node, cost, depth) = frontier.remove(idx);
+
+            if visited.iter().any(|v| *v == node) {
+                continue;
+            }
+            visited.push(node.clone());
+
+            if node == to_id {
+                let mut path = vec![node.s_bs58.clone()];
+                let mut cur = node;
+                while let Some((_, parent)) = parent_of.iter().find(|(child, _)| *child == cur) {
+                    path.push(parent.s_bs58.clone());
+                    cur = parent.clone();
+                }
+                path.reverse();
+                return Some((path, (-cost).exp()));
+            }
+
+            if depth >= max_depth {
+                continue;
+            }
+
+            let user = match self.users.get(&node) {
+                Some(user) => user,
+                None => continue,
+            };
+            for (target, weight) in &user.trust_network {
+                if *weight <= 0.0 {
+                    continue;
+                }
+                let target_id = HashedUserId::from_bs58(target);
+                if target_id == node || visited.iter().any(|v| *v == target_id) {
+                    continue;
+                }
+                if let Some(target_user) = self.users.get(&target_id) {
+                    if target_user.is_blocked(&node.s_bs58) {
+                        continue;
+                    }
+                }
+
+                let next_cost = cost - weight.ln();
+                let existing = best_cost.iter().position(|(id, _)| *id == target_id);
+                let is_improvement = match existing {
+                    Some(i) => next_cost < best_cost[i].1,
+                    None => true,
+                };
+                if !is_improvement {
+                    continue;
+                }
+
+                match existing {
+                    Some(i) => best_cost[i].1 = next_cost,
+                    None => best_cost.push((target_id.clone(), next_cost)),
+                }
+                match parent_of.iter_mut().find(|(child, _)| *child == target_id) {
+                    Some(entry) => entry.1 = node.clone(),
+                    None => parent_of.push((target_id.clone(), node.clone())),
+                }
+                frontier.push((target_id, next_cost, depth + 1));
+            }
+        }
+
+        None
+    }
+
+    // ----------------------------------
+    // TWO-SIDED TRUST REQUESTS
+    // ----------------------------------
+
+    /// Minimum deposit required to open a pending request, sized to cover the
+    /// storage the `TrustRequest` entry itself will occupy. Without this, a
+    /// zero-deposit request costs its spammer nothing to leave behind.
+    fn min_request_deposit() -> u128 {
+        const ENTRY_STORAGE_BYTES: u64 = 256;
+        (ENTRY_STORAGE_BYTES as u128) * env::storage_byte_cost().as_yoctonear()
+    }
+
+    /// Start a time-bounded trust handshake instead of unilaterally writing an
+    /// edge: records a `TrustRequest` keyed by (requester, target) that expires
+    /// `timeout_duration` nanoseconds from now. The attached deposit is held
+    /// until the target accepts (it is returned to the requester) or the request
+    /// expires and anyone reclaims it on the requester's behalf.
+    #[payable]
+    pub fn request_trust(&mut self, target: String) {
+        let requester_id = HashedUserId::from_account_id(&env::predecessor_account_id());
+        let h_target_id = HashedUserId::from_bs58(&target);
+        require!(requester_id != h_target_id, "ERR_CANNOT_REQUEST_SELF");
+        require!(
+            env::attached_deposit().as_yoctonear() >= Self::min_request_deposit(),
+            "ERR_DEPOSIT_TOO_LOW"
+        );
+
+        if let Some(target_user) = self.users.get(&h_target_id) {
+            if target_user.is_blocked(&requester_id.s_bs58) {
+                env::panic_str("You are blocked");
+            }
+        }
+
+        let key = (requester_id, h_target_id);
+        require!(
+            self.pending_requests.get(&key).is_none(),
+            "ERR_REQUEST_ALREADY_PENDING"
+        );
+
+        self.pending_requests.insert(
+            key,
+            TrustRequest {
+                deposit: env::attached_deposit(),
+                expiry: env::block_timestamp() + self.timeout_duration,
+                requester: env::predecessor_account_id(),
+            },
+        );
+    }
+
+    /// Materialize a pending request into a real `trust_network` edge from the
+    /// caller to the requester, and release the requester's held deposit back
+    /// to them.
+    #[payable]
+    pub fn accept_trust_request(&mut self, requester: String, level: f32) {
+        require!(level >= 0.0 && level <= 1.0, "Invalid trust level");
+        let target_id = HashedUserId::from_account_id(&env::predecessor_account_id());
+        let h_requester_id = HashedUserId::from_bs58(&requester);
+
+        let request = self
+            .pending_requests
+            .remove(&(h_requester_id.clone(), target_id.clone()))
+            .unwrap_or_else(|| env::panic_str("No pending request from this user"));
+
+        self.with_user_data(&target_id, |user_data| {
+            user_data.insert_trust_network(h_requester_id.s_bs58.clone(), level);
+        });
+        self.sync_incoming_trust(&target_id, &h_requester_id, Some(level));
+        self.verify_deposit(target_id);
+
+        Promise::new(request.requester).transfer(request.deposit);
+    }
+
+    /// Refund the requester's deposit once their request has gone unanswered
+    /// past its `expiry`. Callable by anyone, not just the requester, so a
+    /// stale entry doesn't sit in storage forever if they never bother to
+    /// clean it up themselves.
+    pub fn reclaim_expired_request(&mut self, requester: String, target: String) {
+        let key = (
+            HashedUserId::from_bs58(&requester),
+            HashedUserId::from_bs58(&target),
+        );
+
+        let request = self
+            .pending_requests
+            .get(&key)
+            .unwrap_or_else(|| env::panic_str("No pending request to reclaim"));
+        require!(
+            env::block_timestamp() > request.expiry,
+            "ERR_REQUEST_NOT_EXPIRED"
+        );
+        let deposit = request.deposit;
+        let requester_account = request.requester.clone();
+
+        self.pending_requests.remove(&key);
+        Promise::new(requester_account).transfer(deposit);
+    }
+
+    /// List pending requests awaiting `user_id`'s decision, as
+    /// `(requester, deposit, expiry)`.
+    pub fn view_pending_requests(&self, user_id: String) -> Vec<(String, NearToken, u64)> {
+        let h_user_id = HashedUserId::from_bs58(&user_id);
+        self.pending_requests
+            .iter()
+            .filter(|((_, target), _)| *target == h_user_id)
+            .map(|((requester, _), request)| {
+                (requester.s_bs58.clone(), request.deposit, request.expiry)
+            })
+            .collect()
+    }
+
     // -------------
     // DELETE ACCOUNT
     // -------------
     #[payable]
     pub fn delete_user(&mut self) {
         let caller_id = HashedUserId::from_account_id(&env::predecessor_account_id());
-        if self.users.get(&caller_id).is_none() {
-            env::panic_str("No record found for this user");
-        }
+        let trust_network = match self.users.get(&caller_id) {
+            Some(user) => user.trust_network.clone(),
+            None => env::panic_str("No record found for this user"),
+        };
         // The deposit the user had staked
         let user_deposit = self
             .user_deposits
@@ -427,6 +942,14 @@ impl CentralLinkOfTrustContract {
             .unwrap_or(&NearToken::from_yoctonear(0))
             .clone();
 
+        // The user's outgoing edges are disappearing along with them, so the
+        // reverse index of whoever they trusted needs to drop those edges too.
+        for (trusted, _) in trust_network {
+            let h_trusted_id = HashedUserId::from_bs58(&trusted);
+            self.sync_incoming_trust(&caller_id, &h_trusted_id, None);
+        }
+        self.incoming_trust.remove(&caller_id);
+
         // Remove from contract
         self.users.remove(&caller_id);
         self.user_deposits.remove(&caller_id);
@@ -440,6 +963,212 @@ impl CentralLinkOfTrustContract {
 mod tests {
     use super::*;
 
+    /// Insert a user with the given outgoing `trust_network` edges directly
+    /// into contract state, bypassing the payable entry points.
+    fn insert_user(contract: &mut CentralLinkOfTrustContract, id: &str, edges: Vec<(&str, f32)>) {
+        let mut user = UserData::new(HashedUserId::from_bs58(id));
+        for (target, weight) in edges {
+            user.insert_trust_network(target.to_string(), weight);
+        }
+        contract.users.insert(HashedUserId::from_bs58(id), user);
+    }
+
+    /// Set up the mocked NEAR runtime context for the given predecessor/deposit.
+    fn set_context(predecessor: &str, attached_deposit: NearToken) {
+        let mut builder = near_sdk::test_utils::VMContextBuilder::new();
+        builder
+            .predecessor_account_id(predecessor.parse().unwrap())
+            .attached_deposit(attached_deposit);
+        near_sdk::testing_env!(builder.build());
+    }
+
+    /// Like `set_context`, but also pins `block_timestamp` (for expiry tests).
+    fn set_context_at(predecessor: &str, attached_deposit: NearToken, block_timestamp: u64) {
+        let mut builder = near_sdk::test_utils::VMContextBuilder::new();
+        builder
+            .predecessor_account_id(predecessor.parse().unwrap())
+            .attached_deposit(attached_deposit)
+            .block_timestamp(block_timestamp);
+        near_sdk::testing_env!(builder.build());
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_REQUEST_ALREADY_PENDING")]
+    fn request_trust_rejects_duplicate_pending_request() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        set_context(
+            "alice.testnet",
+            NearToken::from_yoctonear(CentralLinkOfTrustContract::min_request_deposit()),
+        );
+
+        contract.request_trust("bob".to_string());
+        contract.request_trust("bob".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_DEPOSIT_TOO_LOW")]
+    fn request_trust_rejects_deposit_below_storage_cost() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        set_context("alice.testnet", NearToken::from_yoctonear(0));
+
+        contract.request_trust("bob".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_REQUEST_NOT_EXPIRED")]
+    fn reclaim_expired_request_rejects_before_expiry() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        set_context(
+            "alice.testnet",
+            NearToken::from_yoctonear(CentralLinkOfTrustContract::min_request_deposit()),
+        );
+        contract.request_trust("bob".to_string());
+        let requester_id = HashedUserId::from_account_id(&"alice.testnet".parse().unwrap());
+
+        contract.reclaim_expired_request(requester_id.s_bs58, "bob".to_string());
+    }
+
+    #[test]
+    fn reclaim_expired_request_succeeds_after_expiry() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        set_context(
+            "alice.testnet",
+            NearToken::from_yoctonear(CentralLinkOfTrustContract::min_request_deposit()),
+        );
+        contract.request_trust("bob".to_string());
+        let requester_id = HashedUserId::from_account_id(&"alice.testnet".parse().unwrap());
+
+        // Anyone, not just the requester, can sweep it once expired.
+        set_context_at(
+            "carol.testnet",
+            NearToken::from_yoctonear(0),
+            contract.timeout_duration + 1,
+        );
+        contract.reclaim_expired_request(requester_id.s_bs58, "bob".to_string());
+
+        assert!(contract.view_pending_requests("bob".to_string()).is_empty());
+    }
+
+    #[test]
+    fn accept_trust_request_materializes_edge_and_incoming_trust() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        set_context(
+            "alice.testnet",
+            NearToken::from_yoctonear(CentralLinkOfTrustContract::min_request_deposit()),
+        );
+        let requester_id = HashedUserId::from_account_id(&"alice.testnet".parse().unwrap());
+        let target_id = HashedUserId::from_account_id(&"bob.testnet".parse().unwrap());
+        contract.request_trust(target_id.s_bs58.clone());
+
+        // Accepting grows bob's own trust_network, so it needs a deposit
+        // covering that storage just like `trust` does.
+        set_context("bob.testnet", NearToken::from_yoctonear(10_000_000_000_000_000_000_000));
+        contract.accept_trust_request(requester_id.s_bs58.clone(), 0.8);
+
+        assert_eq!(
+            contract.users[&target_id].get_trust_network(&requester_id.s_bs58),
+            Some(0.8)
+        );
+        assert_eq!(
+            contract.get_trusters(requester_id.s_bs58),
+            vec![(target_id.s_bs58, 0.8)]
+        );
+    }
+
+    #[test]
+    fn sync_incoming_trust_tracks_trusters_and_sum() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        let alice = HashedUserId::from_bs58("alice");
+        let bob = HashedUserId::from_bs58("bob");
+        insert_user(&mut contract, "bob", vec![]);
+
+        contract.sync_incoming_trust(&alice, &bob, Some(0.4));
+        contract.sync_incoming_trust(&alice, &bob, Some(0.9)); // update, not duplicate
+        assert_eq!(
+            contract.get_trusters("bob".to_string()),
+            vec![("alice".to_string(), 0.9)]
+        );
+        assert_eq!(contract.users[&bob].incoming_trust_sum, 0.9);
+
+        contract.sync_incoming_trust(&alice, &bob, None);
+        assert!(contract.get_trusters("bob".to_string()).is_empty());
+        assert_eq!(contract.users[&bob].incoming_trust_sum, 0.0);
+    }
+
+    #[test]
+    fn sync_incoming_trust_does_not_create_unregistered_target() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        let alice = HashedUserId::from_bs58("alice");
+        let bob = HashedUserId::from_bs58("bob");
+
+        // bob never registered (no UserData, no deposit); trusting them must
+        // not conjure a free UserData record just to hold the cached sum.
+        contract.sync_incoming_trust(&alice, &bob, Some(0.4));
+
+        assert!(contract.users.get(&bob).is_none());
+        assert_eq!(
+            contract.get_trusters("bob".to_string()),
+            vec![("alice".to_string(), 0.4)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_NONCE_REPLAYED")]
+    fn trust_signed_rejects_replayed_nonce() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        set_context("relayer.testnet", NearToken::from_yoctonear(0));
+        let signer_key = vec![7u8; 32];
+        contract
+            .signer_nonces
+            .insert(HashedUserId::from_public_key(&signer_key), 5);
+
+        contract.trust_signed("bob".to_string(), 0.5, signer_key, 5, vec![0u8; 64]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ERR_INVALID_SIGNATURE_LEN")]
+    fn trust_signed_rejects_malformed_signature_length() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        set_context("relayer.testnet", NearToken::from_yoctonear(0));
+
+        contract.trust_signed("bob".to_string(), 0.5, vec![7u8; 32], 1, vec![0u8; 10]);
+    }
+
+    #[test]
+    fn compute_global_trust_converges_to_uniform_on_symmetric_cycle() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        insert_user(&mut contract, "alice", vec![("bob", 1.0)]);
+        insert_user(&mut contract, "bob", vec![("carol", 1.0)]);
+        insert_user(&mut contract, "carol", vec![("alice", 1.0)]);
+
+        let scores = contract.compute_global_trust(Vec::new(), 50);
+
+        let total: f32 = scores.iter().map(|(_, v)| v).sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        for (_, v) in &scores {
+            assert!((*v - 1.0 / 3.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn compute_global_trust_falls_back_to_pre_trusted_when_only_edge_is_blocked() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        insert_user(&mut contract, "alice", vec![("bob", 1.0)]);
+        let mut bob = UserData::new(HashedUserId::from_bs58("bob"));
+        bob.block_user("alice".to_string());
+        contract.users.insert(HashedUserId::from_bs58("bob"), bob);
+
+        // alice's only outgoing edge targets a user who blocks her, so her row
+        // falls back to the pre_trusted distribution instead of a dangling edge.
+        let scores = contract.compute_global_trust(vec!["bob".to_string()], 10);
+        let bob_score = scores
+            .iter()
+            .find(|(id, _)| id == "bob")
+            .map(|(_, v)| *v)
+            .unwrap();
+        assert!(bob_score > 0.0);
+    }
+
     #[test]
     fn user_data_trust_network_insertion() {
         let mut user = UserData::new(HashedUserId::from_bs58("alice"));
@@ -553,4 +1282,47 @@ mod tests {
         alice.insert_trust_network("bob".to_string(), 1.0);
         assert_eq!(alice.get_trust_network("bob").unwrap(), 1.0);
     }
+
+    #[test]
+    fn strongest_trust_path_prefers_higher_confidence_route() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        insert_user(&mut contract, "alice", vec![("bob", 0.9), ("carol", 0.5)]);
+        insert_user(&mut contract, "bob", vec![("dave", 0.9)]);
+        insert_user(&mut contract, "carol", vec![("dave", 0.9)]);
+        insert_user(&mut contract, "dave", vec![]);
+
+        let (path, confidence) = contract
+            .strongest_trust_path("alice".to_string(), "dave".to_string(), 5)
+            .expect("a path should exist");
+        assert_eq!(path, vec!["alice", "bob", "dave"]);
+        assert!((confidence - 0.81).abs() < 1e-4);
+    }
+
+    #[test]
+    fn strongest_trust_path_returns_none_when_target_blocks_the_predecessor() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        insert_user(&mut contract, "alice", vec![("bob", 1.0)]);
+        let mut bob = UserData::new(HashedUserId::from_bs58("bob"));
+        bob.block_user("alice".to_string());
+        contract.users.insert(HashedUserId::from_bs58("bob"), bob);
+
+        assert!(contract
+            .strongest_trust_path("alice".to_string(), "bob".to_string(), 5)
+            .is_none());
+    }
+
+    #[test]
+    fn strongest_trust_path_respects_max_depth() {
+        let mut contract = CentralLinkOfTrustContract::default();
+        insert_user(&mut contract, "alice", vec![("bob", 1.0)]);
+        insert_user(&mut contract, "bob", vec![("carol", 1.0)]);
+        insert_user(&mut contract, "carol", vec![]);
+
+        assert!(contract
+            .strongest_trust_path("alice".to_string(), "carol".to_string(), 1)
+            .is_none());
+        assert!(contract
+            .strongest_trust_path("alice".to_string(), "carol".to_string(), 2)
+            .is_some());
+    }
 }